@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use steamgriddb_api::{search::SearchResult, Client};
+
+/// How long a negative result (no game match, or no art of a given type) is
+/// trusted before we bother SteamGridDB about it again. Long enough to avoid
+/// hammering the API on every run, short enough to pick up art that gets
+/// added to the site later.
+const CACHE_ENTRY_TTL_DAYS: u64 = 14;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+struct CacheEntry {
+    /// The resolved SteamGridDB game id, once a search has matched one.
+    grid_id: Option<usize>,
+    /// Set when a search came back with no match, so we stop re-querying a
+    /// title SteamGridDB doesn't know about.
+    not_found_at: Option<u64>,
+    /// `ImageType::file_name()` -> the time we learned SteamGridDB has no art
+    /// of that type for this game.
+    no_art_at: HashMap<String, u64>,
+}
+
+impl CacheEntry {
+    fn is_stale(timestamp: u64, now: u64) -> bool {
+        now.saturating_sub(timestamp) > CACHE_ENTRY_TTL_DAYS * 24 * 60 * 60
+    }
+}
+
+fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+fn default_cache_path() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("boilr")
+        .join("steamgriddb_cache.json")
+}
+
+/// Wraps the SteamGridDB search and image APIs with a persistent, on-disk
+/// cache, so a large library doesn't re-query the same un-findable games (or
+/// re-check for art SteamGridDB doesn't have) on every single run.
+pub struct CachedSearch<'a> {
+    client: &'a Client,
+    cache_path: PathBuf,
+    cache: HashMap<u32, CacheEntry>,
+}
+
+impl<'a> CachedSearch<'a> {
+    pub fn new(client: &'a Client) -> Self {
+        let cache_path = default_cache_path();
+        let cache = std::fs::read_to_string(&cache_path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        CachedSearch {
+            client,
+            cache_path,
+            cache,
+        }
+    }
+
+    pub async fn search(
+        &mut self,
+        app_id: u32,
+        app_name: &str,
+    ) -> Result<Option<usize>, Box<dyn std::error::Error>> {
+        let now = now_unix();
+        if let Some(entry) = self.cache.get(&app_id) {
+            if let Some(grid_id) = entry.grid_id {
+                return Ok(Some(grid_id));
+            }
+            if let Some(not_found_at) = entry.not_found_at {
+                if !CacheEntry::is_stale(not_found_at, now) {
+                    return Ok(None);
+                }
+            }
+        }
+
+        let results: Vec<SearchResult> = self.client.search(app_name).await?;
+        let entry = self.cache.entry(app_id).or_default();
+        match results.first().map(|result| result.id) {
+            Some(grid_id) => {
+                entry.grid_id = Some(grid_id);
+                entry.not_found_at = None;
+                Ok(Some(grid_id))
+            }
+            None => {
+                entry.not_found_at = Some(now);
+                println!(
+                    "No SteamGridDB match for \"{}\" - consider submitting it at https://www.steamgriddb.com/game/new",
+                    app_name
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    /// Whether we already know SteamGridDB has no art of this file name for
+    /// this app, so the download loop can skip it without a request.
+    pub fn has_no_art(&self, app_id: u32, image_file_name: &str) -> bool {
+        let now = now_unix();
+        self.cache
+            .get(&app_id)
+            .and_then(|entry| entry.no_art_at.get(image_file_name))
+            .is_some_and(|timestamp| !CacheEntry::is_stale(*timestamp, now))
+    }
+
+    pub fn record_no_art(&mut self, app_id: u32, image_file_name: &str) {
+        let entry = self.cache.entry(app_id).or_default();
+        entry.no_art_at.insert(image_file_name.to_string(), now_unix());
+    }
+
+    pub fn save(&self) {
+        if let Some(parent) = self.cache_path.parent() {
+            if std::fs::create_dir_all(parent).is_err() {
+                return;
+            }
+        }
+        if let Ok(content) = serde_json::to_string(&self.cache) {
+            let _ = std::fs::write(&self.cache_path, content);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY_SECS: u64 = 24 * 60 * 60;
+
+    fn search_with_cache(cache: HashMap<u32, CacheEntry>, client: &Client) -> CachedSearch<'_> {
+        CachedSearch {
+            client,
+            cache_path: PathBuf::from("/dev/null"),
+            cache,
+        }
+    }
+
+    #[test]
+    fn fresh_entry_is_not_stale() {
+        let now = now_unix();
+        assert!(!CacheEntry::is_stale(now, now));
+        assert!(!CacheEntry::is_stale(
+            now - CACHE_ENTRY_TTL_DAYS * DAY_SECS + 1,
+            now
+        ));
+    }
+
+    #[test]
+    fn entry_older_than_ttl_is_stale() {
+        let now = now_unix();
+        assert!(CacheEntry::is_stale(
+            now - CACHE_ENTRY_TTL_DAYS * DAY_SECS - 1,
+            now
+        ));
+    }
+
+    #[test]
+    fn has_no_art_true_right_after_recording() {
+        let client = Client::new("test-auth-key".to_string());
+        let mut search = search_with_cache(HashMap::new(), &client);
+        assert!(!search.has_no_art(42, "42_hero.png"));
+
+        search.record_no_art(42, "42_hero.png");
+        assert!(search.has_no_art(42, "42_hero.png"));
+        // a different image type for the same app wasn't recorded
+        assert!(!search.has_no_art(42, "42_logo.png"));
+    }
+
+    #[test]
+    fn has_no_art_expires_after_ttl() {
+        let client = Client::new("test-auth-key".to_string());
+        let now = now_unix();
+        let mut entry = CacheEntry::default();
+        entry
+            .no_art_at
+            .insert("42_hero.png".to_string(), now - CACHE_ENTRY_TTL_DAYS * DAY_SECS - 1);
+        let mut cache = HashMap::new();
+        cache.insert(42, entry);
+
+        let search = search_with_cache(cache, &client);
+        assert!(!search.has_no_art(42, "42_hero.png"));
+    }
+
+    #[test]
+    fn recording_no_art_does_not_clobber_a_resolved_grid_id() {
+        let client = Client::new("test-auth-key".to_string());
+        let mut entry = CacheEntry::default();
+        entry.grid_id = Some(7);
+        let mut cache = HashMap::new();
+        cache.insert(42, entry);
+
+        let mut search = search_with_cache(cache, &client);
+        search.record_no_art(42, "42_hero.png");
+
+        assert_eq!(search.cache.get(&42).unwrap().grid_id, Some(7));
+        assert!(search.has_no_art(42, "42_hero.png"));
+    }
+}
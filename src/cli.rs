@@ -0,0 +1,51 @@
+use argh::FromArgs;
+
+#[derive(FromArgs)]
+/// Keep your non-Steam games in sync with your Steam library.
+pub struct BoilrArgs {
+    #[argh(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum Command {
+    Sync(SyncCommand),
+    Add(AddCommand),
+    List(ListCommand),
+}
+
+#[derive(FromArgs)]
+/// scan all enabled launchers and fetch artwork (this is the default when no subcommand is given)
+#[argh(subcommand, name = "sync")]
+pub struct SyncCommand {}
+
+#[derive(FromArgs)]
+/// add a single non-Steam shortcut by hand
+#[argh(subcommand, name = "add")]
+pub struct AddCommand {
+    #[argh(option, short = 'n')]
+    /// the name shown in the Steam library
+    pub name: String,
+
+    #[argh(option, short = 'd')]
+    /// the working directory the executable is launched from
+    pub start_dir: String,
+
+    #[argh(option, short = 'i', default = "String::new()")]
+    /// path to an icon file
+    pub icon: String,
+
+    #[argh(positional, greedy)]
+    /// the executable followed by any arguments to pass to it
+    pub command: Vec<String>,
+}
+
+#[derive(FromArgs)]
+/// list the shortcuts already in the Steam library
+#[argh(subcommand, name = "list")]
+pub struct ListCommand {
+    #[argh(switch, short = 'v')]
+    /// print every field of each shortcut instead of just the name, exe and tags
+    pub verbose: bool,
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+use steam_shortcuts_util::shortcut::ShortcutOwned;
+
+use crate::platform::Platform;
+use crate::settings::EpicGamesSettings;
+
+#[derive(Debug)]
+pub struct EpicGamesError {
+    pub message: String,
+}
+
+impl fmt::Display for EpicGamesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for EpicGamesError {}
+
+pub struct EpicPlatform {
+    settings: EpicGamesSettings,
+}
+
+impl EpicPlatform {
+    pub fn new(settings: EpicGamesSettings) -> Self {
+        EpicPlatform { settings }
+    }
+}
+
+impl Platform<ShortcutOwned, EpicGamesError> for EpicPlatform {
+    fn enabled(&self) -> bool {
+        self.settings.enabled
+    }
+
+    fn name(&self) -> &str {
+        "Epic"
+    }
+
+    fn get_shortcuts(&self) -> Result<Vec<ShortcutOwned>, EpicGamesError> {
+        Ok(vec![])
+    }
+}
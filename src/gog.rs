@@ -0,0 +1,243 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use steam_shortcuts_util::shortcut::ShortcutOwned;
+
+use crate::platform::Platform;
+use crate::settings::GogSettings;
+
+#[derive(Debug)]
+pub struct GogError {
+    pub message: String,
+}
+
+impl fmt::Display for GogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for GogError {}
+
+pub struct GogPlatform {
+    settings: GogSettings,
+}
+
+impl GogPlatform {
+    pub fn new(settings: GogSettings) -> Self {
+        GogPlatform { settings }
+    }
+
+    /// GOG Galaxy keeps its install catalog in a SQLite database, typically
+    /// at `%PROGRAMDATA%\GOG.com\Galaxy\storage\galaxy-2.0.db`.
+    #[cfg(target_os = "windows")]
+    fn database_path(&self) -> Option<PathBuf> {
+        if let Some(path) = &self.settings.galaxy_db_path {
+            return Some(PathBuf::from(path));
+        }
+        let program_data = std::env::var("PROGRAMDATA").ok()?;
+        Some(PathBuf::from(program_data).join("GOG.com/Galaxy/storage/galaxy-2.0.db"))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn database_path(&self) -> Option<PathBuf> {
+        self.settings.galaxy_db_path.as_ref().map(PathBuf::from)
+    }
+
+    fn read_owned_games(&self) -> Result<Vec<ShortcutOwned>, GogError> {
+        let db_path = match self.database_path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(vec![]),
+        };
+
+        let connection = rusqlite::Connection::open(&db_path).map_err(|err| GogError {
+            message: format!("Could not open Galaxy database: {}", err),
+        })?;
+        let games = read_catalog(&connection)?;
+
+        let mut shortcuts = vec![];
+        for (product_id, name, install_path) in games {
+            let (exe, start_dir) = match read_primary_launch_exe(&connection, &product_id) {
+                // The launch params table stores the exe path relative to the
+                // install dir, same as Galaxy itself resolves it at launch.
+                Some(relative_exe) => (
+                    PathBuf::from(&install_path)
+                        .join(relative_exe)
+                        .to_string_lossy()
+                        .to_string(),
+                    install_path,
+                ),
+                // No play task recorded (e.g. a DLC-only entry, or a schema
+                // Galaxy version we don't recognize) - fall back to opening
+                // the product's game view in the Galaxy client itself, much
+                // as origin.rs/ubisoft.rs go through their own launchers.
+                None => (format!("goggalaxy://openGameView/{}", product_id), String::new()),
+            };
+            let app_id =
+                steam_shortcuts_util::app_id_generator::calculate_app_id(&exe, &name);
+            let shortcut = steam_shortcuts_util::shortcut::Shortcut::new(
+                app_id,
+                &name,
+                &exe,
+                &start_dir,
+                "",
+                "",
+                "",
+            );
+            let mut shortcut_owned = shortcut.to_owned();
+            shortcut_owned.tags.push(self.name().to_string());
+            shortcuts.push(shortcut_owned);
+        }
+        Ok(shortcuts)
+    }
+}
+
+/// Read the (product id, title, install path) triple for every installed
+/// product out of an open Galaxy database connection.
+fn read_catalog(
+    connection: &rusqlite::Connection,
+) -> Result<Vec<(String, String, String)>, GogError> {
+    let mut statement = connection
+        .prepare(
+            "SELECT InstalledBaseProducts.productId, ProductNames.title, installationPath \
+             FROM InstalledBaseProducts \
+             INNER JOIN ProductNames ON ProductNames.productId = InstalledBaseProducts.productId",
+        )
+        .map_err(|err| GogError {
+            message: format!("Could not query Galaxy database: {}", err),
+        })?;
+    let games = statement
+        .query_map([], |row| {
+            let product_id: String = row.get(0)?;
+            let name: String = row.get(1)?;
+            let path: String = row.get(2)?;
+            Ok((product_id, name, path))
+        })
+        .map_err(|err| GogError {
+            message: format!("Could not read Galaxy catalog: {}", err),
+        })?;
+    Ok(games.filter_map(|g| g.ok()).collect())
+}
+
+/// Read the executable path (relative to the install dir) that Galaxy itself
+/// launches for a product, from `PlayTasks`/`PlayTaskLaunchParameters`.
+/// `PlayTasks.gameReleaseKey` is the product id prefixed with `gog_`, and the
+/// primary task (`isPrimary`) is the one the "Play" button in Galaxy runs.
+fn read_primary_launch_exe(connection: &rusqlite::Connection, product_id: &str) -> Option<String> {
+    // Errors here (missing rows, or the launch-params tables not existing in
+    // an older/newer Galaxy schema) just mean `read_owned_games` falls back to
+    // the Galaxy URI - they shouldn't stop the rest of the catalog loading.
+    connection
+        .query_row(
+            "SELECT PlayTaskLaunchParameters.executablePath FROM PlayTasks \
+             INNER JOIN PlayTaskLaunchParameters ON PlayTaskLaunchParameters.playTaskId = PlayTasks.id \
+             WHERE PlayTasks.gameReleaseKey = ?1 AND PlayTasks.isPrimary = 1",
+            rusqlite::params![format!("gog_{}", product_id)],
+            |row| row.get(0),
+        )
+        .ok()
+}
+
+impl Platform<ShortcutOwned, GogError> for GogPlatform {
+    fn enabled(&self) -> bool {
+        self.settings.enabled
+    }
+
+    fn name(&self) -> &str {
+        "GOG"
+    }
+
+    fn get_shortcuts(&self) -> Result<Vec<ShortcutOwned>, GogError> {
+        self.read_owned_games()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn in_memory_catalog(rows: &[(&str, &str, &str)]) -> rusqlite::Connection {
+        let connection = rusqlite::Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE InstalledBaseProducts (productId TEXT, installationPath TEXT);
+                 CREATE TABLE ProductNames (productId TEXT, title TEXT);
+                 CREATE TABLE PlayTasks (id INTEGER PRIMARY KEY, gameReleaseKey TEXT, isPrimary BOOL);
+                 CREATE TABLE PlayTaskLaunchParameters (playTaskId INTEGER, executablePath TEXT);",
+            )
+            .unwrap();
+        for (product_id, title, install_path) in rows {
+            connection
+                .execute(
+                    "INSERT INTO InstalledBaseProducts (productId, installationPath) VALUES (?1, ?2)",
+                    rusqlite::params![product_id, install_path],
+                )
+                .unwrap();
+            connection
+                .execute(
+                    "INSERT INTO ProductNames (productId, title) VALUES (?1, ?2)",
+                    rusqlite::params![product_id, title],
+                )
+                .unwrap();
+        }
+        connection
+    }
+
+    fn add_primary_launch_task(connection: &rusqlite::Connection, product_id: &str, exe: &str) {
+        connection
+            .execute(
+                "INSERT INTO PlayTasks (gameReleaseKey, isPrimary) VALUES (?1, 1)",
+                rusqlite::params![format!("gog_{}", product_id)],
+            )
+            .unwrap();
+        let task_id = connection.last_insert_rowid();
+        connection
+            .execute(
+                "INSERT INTO PlayTaskLaunchParameters (playTaskId, executablePath) VALUES (?1, ?2)",
+                rusqlite::params![task_id, exe],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn reads_the_game_title_not_the_product_id() {
+        let connection = in_memory_catalog(&[("1234567890", "Witcher 3", r"C:\Games\Witcher 3")]);
+        let games = read_catalog(&connection).unwrap();
+        assert_eq!(
+            games,
+            vec![(
+                "1234567890".to_string(),
+                "Witcher 3".to_string(),
+                r"C:\Games\Witcher 3".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn reads_multiple_installed_games() {
+        let connection = in_memory_catalog(&[
+            ("1", "Game One", "/games/one"),
+            ("2", "Game Two", "/games/two"),
+        ]);
+        let games = read_catalog(&connection).unwrap();
+        assert_eq!(games.len(), 2);
+        assert!(games.contains(&("1".to_string(), "Game One".to_string(), "/games/one".to_string())));
+        assert!(games.contains(&("2".to_string(), "Game Two".to_string(), "/games/two".to_string())));
+    }
+
+    #[test]
+    fn reads_the_primary_play_task_executable() {
+        let connection = in_memory_catalog(&[("1207658930", "Witcher 3", r"C:\Games\Witcher 3")]);
+        add_primary_launch_task(&connection, "1207658930", "witcher3.exe");
+
+        let exe = read_primary_launch_exe(&connection, "1207658930");
+        assert_eq!(exe, Some("witcher3.exe".to_string()));
+    }
+
+    #[test]
+    fn returns_none_when_no_play_task_is_recorded() {
+        let connection = in_memory_catalog(&[("1", "Game One", "/games/one")]);
+        let exe = read_primary_launch_exe(&connection, "1");
+        assert_eq!(exe, None);
+    }
+}
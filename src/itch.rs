@@ -0,0 +1,154 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use steam_shortcuts_util::shortcut::ShortcutOwned;
+
+use crate::platform::Platform;
+use crate::settings::ItchSettings;
+
+#[derive(Debug)]
+pub struct ItchError {
+    pub message: String,
+}
+
+impl fmt::Display for ItchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ItchError {}
+
+pub struct ItchPlatform {
+    settings: ItchSettings,
+}
+
+impl ItchPlatform {
+    pub fn new(settings: ItchSettings) -> Self {
+        ItchPlatform { settings }
+    }
+
+    /// itch.io's butler installer tracks installed "caves" (cave = one
+    /// installed build of a game) in a SQLite database under the itch
+    /// config folder.
+    fn database_path(&self) -> Option<PathBuf> {
+        if let Some(path) = &self.settings.butler_db_path {
+            return Some(PathBuf::from(path));
+        }
+        #[cfg(target_os = "windows")]
+        let config_dir = std::env::var("APPDATA").ok().map(PathBuf::from);
+        #[cfg(not(target_os = "windows"))]
+        let config_dir = std::env::var("HOME")
+            .ok()
+            .map(|home| PathBuf::from(home).join(".config"));
+
+        config_dir.map(|dir| dir.join("itch/db/butler.db"))
+    }
+
+    fn read_installed_caves(&self) -> Result<Vec<ShortcutOwned>, ItchError> {
+        let db_path = match self.database_path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(vec![]),
+        };
+
+        let connection = rusqlite::Connection::open(&db_path).map_err(|err| ItchError {
+            message: format!("Could not open butler database: {}", err),
+        })?;
+        let mut statement = connection
+            .prepare("SELECT games.title, caves.verdict FROM caves INNER JOIN games ON games.id = caves.game_id")
+            .map_err(|err| ItchError {
+                message: format!("Could not query butler database: {}", err),
+            })?;
+        let caves = statement
+            .query_map([], |row| {
+                let title: String = row.get(0)?;
+                let verdict: String = row.get(1)?;
+                Ok((title, verdict))
+            })
+            .map_err(|err| ItchError {
+                message: format!("Could not read installed games: {}", err),
+            })?;
+
+        let mut shortcuts = vec![];
+        for (title, verdict) in caves.filter_map(|c| c.ok()) {
+            let (base_path, exe_relative_path) = match parse_verdict(&verdict) {
+                Some(parsed) => parsed,
+                None => continue,
+            };
+            let exe = PathBuf::from(&base_path).join(&exe_relative_path);
+            let app_id = steam_shortcuts_util::app_id_generator::calculate_app_id(
+                exe.to_string_lossy().as_ref(),
+                &title,
+            );
+            let shortcut = steam_shortcuts_util::shortcut::Shortcut::new(
+                app_id,
+                &title,
+                &exe.to_string_lossy(),
+                &base_path,
+                "",
+                "",
+                "",
+            );
+            let mut shortcut_owned = shortcut.to_owned();
+            shortcut_owned.tags.push(self.name().to_string());
+            shortcuts.push(shortcut_owned);
+        }
+        Ok(shortcuts)
+    }
+}
+
+/// Pull the install directory and the first launch candidate's exe path
+/// (relative to that directory) out of butler's `verdict` JSON blob. It looks
+/// roughly like:
+/// `{"basePath":"/install/dir","candidates":[{"path":"game.exe",...}]}`
+fn parse_verdict(verdict: &str) -> Option<(String, String)> {
+    let verdict: serde_json::Value = serde_json::from_str(verdict).ok()?;
+    let base_path = verdict.get("basePath")?.as_str()?.to_string();
+    let exe_relative_path = verdict
+        .get("candidates")?
+        .as_array()?
+        .first()?
+        .get("path")?
+        .as_str()?
+        .to_string();
+    Some((base_path, exe_relative_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_base_path_and_first_candidate() {
+        let verdict = r#"{"basePath":"/home/user/.config/itch/apps/game","totalSize":123,"candidates":[{"path":"game.exe","flavor":"windows","arch":"386"},{"path":"game-debug.exe","flavor":"windows","arch":"386"}]}"#;
+        let (base_path, exe_relative_path) = parse_verdict(verdict).unwrap();
+        assert_eq!(base_path, "/home/user/.config/itch/apps/game");
+        assert_eq!(exe_relative_path, "game.exe");
+    }
+
+    #[test]
+    fn missing_candidates_returns_none() {
+        let verdict = r#"{"basePath":"/home/user/.config/itch/apps/game","totalSize":123}"#;
+        assert_eq!(parse_verdict(verdict), None);
+    }
+
+    #[test]
+    fn missing_base_path_returns_none() {
+        let verdict = r#"{"candidates":[{"path":"game.exe"}]}"#;
+        assert_eq!(parse_verdict(verdict), None);
+    }
+}
+
+impl Platform<ShortcutOwned, ItchError> for ItchPlatform {
+    fn enabled(&self) -> bool {
+        self.settings.enabled
+    }
+
+    fn name(&self) -> &str {
+        "itch.io"
+    }
+
+    fn get_shortcuts(&self) -> Result<Vec<ShortcutOwned>, ItchError> {
+        self.read_installed_caves()
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+use steam_shortcuts_util::shortcut::ShortcutOwned;
+
+use crate::platform::Platform;
+use crate::settings::LegendarySettings;
+
+#[derive(Debug)]
+pub struct LegendaryError {
+    pub message: String,
+}
+
+impl fmt::Display for LegendaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for LegendaryError {}
+
+pub struct LegendaryPlatform {
+    settings: LegendarySettings,
+}
+
+impl LegendaryPlatform {
+    pub fn new(settings: LegendarySettings) -> Self {
+        LegendaryPlatform { settings }
+    }
+}
+
+impl Platform<ShortcutOwned, LegendaryError> for LegendaryPlatform {
+    fn enabled(&self) -> bool {
+        self.settings.enabled
+    }
+
+    fn name(&self) -> &str {
+        "Legendary"
+    }
+
+    fn get_shortcuts(&self) -> Result<Vec<ShortcutOwned>, LegendaryError> {
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,129 @@
+use std::path::Path;
+
+/// One entry from `config/loginusers.vdf`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginUser {
+    pub steam_id64: u64,
+    pub persona_name: String,
+    pub most_recent: bool,
+}
+
+impl LoginUser {
+    /// The 32-bit account id that names a user's `userdata` folder.
+    pub fn account_id(&self) -> u32 {
+        (self.steam_id64 & 0xFFFF_FFFF) as u32
+    }
+}
+
+/// A steamid64 is 17 decimal digits, long enough to tell apart from the
+/// short numeric keys (`RememberPassword`, `MostRecent`, ...) inside a block.
+fn looks_like_steam_id64(token: &str) -> bool {
+    token.len() >= 15 && token.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Parse the quoted tokens out of a text VDF file, ignoring braces.
+fn tokenize(content: &str) -> Vec<&str> {
+    content
+        .split('"')
+        .enumerate()
+        .filter_map(|(i, token)| if i % 2 == 1 { Some(token) } else { None })
+        .collect()
+}
+
+/// Parse `<steam>/config/loginusers.vdf` into the list of known users.
+pub fn parse_login_users(content: &str) -> Vec<LoginUser> {
+    let mut users = vec![];
+    let mut current: Option<LoginUser> = None;
+    let mut pending_key: Option<&str> = None;
+
+    for token in tokenize(content) {
+        if looks_like_steam_id64(token) {
+            if let Some(user) = current.take() {
+                users.push(user);
+            }
+            current = Some(LoginUser {
+                steam_id64: token.parse().unwrap_or_default(),
+                persona_name: String::new(),
+                most_recent: false,
+            });
+            pending_key = None;
+            continue;
+        }
+
+        match pending_key.take() {
+            None => pending_key = Some(token),
+            Some(key) => {
+                if let Some(user) = current.as_mut() {
+                    match key {
+                        "PersonaName" => user.persona_name = token.to_string(),
+                        "MostRecent" => user.most_recent = token == "1",
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    if let Some(user) = current.take() {
+        users.push(user);
+    }
+    users
+}
+
+/// Read and parse `<steam>/config/loginusers.vdf`, returning an empty list
+/// if it is missing (e.g. a fresh Steam install with no logged-in users).
+pub fn get_users(steam_path: &Path) -> Vec<LoginUser> {
+    let path = steam_path.join("config/loginusers.vdf");
+    match std::fs::read_to_string(path) {
+        Ok(content) => parse_login_users(&content),
+        Err(_) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#""users"
+{
+	"76561198012345678"
+	{
+		"AccountName"		"somebody"
+		"PersonaName"		"snowflurry"
+		"RememberPassword"		"1"
+		"MostRecent"		"1"
+		"Timestamp"		"1234567890"
+	}
+	"76561198000000022"
+	{
+		"AccountName"		"someoneelse"
+		"PersonaName"		"frost"
+		"RememberPassword"		"0"
+		"MostRecent"		"0"
+		"Timestamp"		"987654321"
+	}
+}
+"#;
+
+    #[test]
+    fn parses_multiple_users() {
+        let users = parse_login_users(EXAMPLE);
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].persona_name, "snowflurry");
+        assert!(users[0].most_recent);
+        assert_eq!(users[1].persona_name, "frost");
+        assert!(!users[1].most_recent);
+    }
+
+    #[test]
+    fn converts_steam_id64_to_account_id() {
+        let users = parse_login_users(EXAMPLE);
+        let expected = (76561198012345678u64 & 0xFFFF_FFFF) as u32;
+        assert_eq!(users[0].account_id(), expected);
+    }
+
+    #[test]
+    fn missing_file_returns_empty() {
+        let users = get_users(Path::new("/does/not/exist"));
+        assert!(users.is_empty());
+    }
+}
@@ -1,7 +1,6 @@
 use std::{
     borrow::Borrow,
     collections::HashMap,
-    env::{self},
     fmt,
     fs::File,
     io::Write,
@@ -9,21 +8,38 @@ use std::{
     path::Path,
 };
 mod cached_search;
+mod cli;
 mod egs;
+mod gog;
+mod itch;
 mod legendary;
+mod loginusers;
+mod origin;
 mod platform;
 mod settings;
+mod steam_location;
 mod steamgriddb;
+mod ubisoft;
+mod web_launcher;
 
 use crate::{
-    egs::EpicPlatform, legendary::LegendaryPlatform, platform::Platform, settings::Settings,
+    cli::{AddCommand, BoilrArgs, Command, ListCommand},
+    egs::EpicPlatform,
+    gog::GogPlatform,
+    itch::ItchPlatform,
+    legendary::LegendaryPlatform,
+    loginusers::LoginUser,
+    origin::OriginPlatform,
+    platform::Platform,
+    settings::Settings,
+    ubisoft::UbisoftPlatform,
+    web_launcher::WebLauncherPlatform,
 };
 use std::error::Error;
 use steam_shortcuts_util::{
-    parse_shortcuts, shortcut::ShortcutOwned, shortcuts_to_bytes, Shortcut,
+    app_id_generator::calculate_app_id, parse_shortcuts, shortcut::ShortcutOwned,
+    shortcuts_to_bytes, Shortcut,
 };
-use steamgriddb_api::{search::SearchResult, Client};
-
 use crate::cached_search::CachedSearch;
 
 pub struct ShortcutInfo {
@@ -42,14 +58,14 @@ fn get_shortcuts_for_user(user: &SteamUsersInfo) -> ShortcutInfo {
             .map(|s| s.to_owned())
             .collect();
         println!(
-            "Found {} shortcuts , for user: {}",
+            "Found {} shortcuts, for user: {}",
             shortcuts.len(),
-            user.steam_user_data_folder
+            user.display_name()
         );
     } else {
         println!(
             "Did not find a shortcut file for user {}, createing a new",
-            user.steam_user_data_folder
+            user.display_name()
         );
         std::fs::create_dir_all(format!("{}/{}", user.steam_user_data_folder, "config")).unwrap();
         new_path = Some(format!(
@@ -65,8 +81,17 @@ fn get_shortcuts_for_user(user: &SteamUsersInfo) -> ShortcutInfo {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    let args: BoilrArgs = argh::from_env();
     let settings = Settings::new()?;
 
+    match args.command {
+        None | Some(Command::Sync(_)) => run_sync(settings).await,
+        Some(Command::Add(add)) => add_shortcut(add),
+        Some(Command::List(list)) => list_shortcuts(list),
+    }
+}
+
+async fn run_sync(settings: Settings) -> Result<(), Box<dyn Error>> {
     let auth_key = settings.steamgrid_db.auth_key;
     if settings.steamgrid_db.enabled && auth_key.is_none() {
         println!("auth_key not found, please add it to the steamgrid_db settings ");
@@ -75,7 +100,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     let auth_key = auth_key.unwrap();
 
-    let client = steamgriddb_api::Client::new(auth_key);
+    let client = steamgriddb::new_client(auth_key);
     let mut search = CachedSearch::new(&client);
 
     let userinfo_shortcuts = get_shortcuts_paths()?;
@@ -96,6 +121,31 @@ async fn main() -> Result<(), Box<dyn Error>> {
             &mut new_user_shortcuts,
         );
 
+        update_platform_shortcuts(
+            &GogPlatform::new(settings.gog.clone()),
+            &mut new_user_shortcuts,
+        );
+
+        update_platform_shortcuts(
+            &ItchPlatform::new(settings.itch.clone()),
+            &mut new_user_shortcuts,
+        );
+
+        update_platform_shortcuts(
+            &OriginPlatform::new(settings.origin.clone()),
+            &mut new_user_shortcuts,
+        );
+
+        update_platform_shortcuts(
+            &UbisoftPlatform::new(settings.ubisoft.clone()),
+            &mut new_user_shortcuts,
+        );
+
+        update_platform_shortcuts(
+            &WebLauncherPlatform::new(settings.web_launcher.clone()),
+            &mut new_user_shortcuts,
+        );
+
         let shortcuts = new_user_shortcuts.iter().map(|f| f.borrow()).collect();
 
         let new_content = shortcuts_to_bytes(&shortcuts);
@@ -105,42 +155,51 @@ async fn main() -> Result<(), Box<dyn Error>> {
         let known_images = get_users_images(user).unwrap();
         // let mut hash_map = HashMap::new();
 
+        let all_image_types = [
+            ImageType::Hero,
+            ImageType::Grid,
+            ImageType::WideGrid,
+            ImageType::Logo,
+            ImageType::Icon,
+        ];
+
         let shortcuts_to_search_for = shortcuts.iter().filter(|s| {
-            let images = vec![
-                format!("{}_hero.png", s.app_id),
-                format!("{}p.png", s.app_id),
-                format!("{}_logo.png", s.app_id),
-            ];
             // if we are missing any of the images we need to search for them
-            images.iter().any(|image| !known_images.contains(&image))
+            all_image_types
+                .iter()
+                .any(|image_type| !known_images.contains(&image_type.file_name(s.app_id)))
         });
 
         let mut search_results = HashMap::new();
         for s in shortcuts_to_search_for {
             println!("Searching for {}", s.app_name);
-            let search = search.search(s.app_id, s.app_name).await?;
-            if let Some(search) = search {
-                search_results.insert(s.app_id, search);
+            let search_result = search.search(s.app_id, s.app_name).await;
+            // Persist after every lookup so a failure partway through a large
+            // library (timeout, rate limit, ...) doesn't throw away every
+            // grid-id and not-found entry found so far.
+            search.save();
+            if let Some(grid_id) = search_result? {
+                search_results.insert(s.app_id, grid_id);
             }
         }
 
-        let types = vec![ImageType::Logo, ImageType::Hero, ImageType::Grid];
-        for image_type in types {
-            let mut images_needed = shortcuts
+        for image_type in all_image_types {
+            let images_needed: Vec<_> = shortcuts
                 .iter()
                 .filter(|s| search_results.contains_key(&s.app_id))
-                .filter(|s| !known_images.contains(&image_type.file_name(s.app_id)));
+                .filter(|s| !known_images.contains(&image_type.file_name(s.app_id)))
+                // shortcuts that already bring their own icon don't need one from SteamGridDB
+                .filter(|s| !(matches!(image_type, ImageType::Icon) && !s.icon.is_empty()))
+                .filter(|s| !search.has_no_art(s.app_id, &image_type.file_name(s.app_id)))
+                .collect();
             let image_ids: Vec<usize> = images_needed
-                .clone()
+                .iter()
                 .filter_map(|s| search_results.get(&s.app_id))
                 .map(|search| *search)
                 .collect();
+            let mut images_needed = images_needed.into_iter();
 
-            let query_type = match image_type {
-                ImageType::Hero => steamgriddb_api::query_parameters::QueryType::Hero(None),
-                ImageType::Grid => steamgriddb_api::query_parameters::QueryType::Grid(None),
-                ImageType::Logo => steamgriddb_api::query_parameters::QueryType::Logo(None),
-            };
+            let query_type = image_type.query_type();
 
             match client
                 .get_images_for_ids(image_ids.as_slice(), &query_type)
@@ -149,10 +208,11 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 Ok(images) => {
                     for image in images {
                         if let Some(shortcut) = images_needed.next() {
+                            let image_file_name = image_type.file_name(shortcut.app_id);
                             if let Ok(image) = image {
                                 let grid_folder = Path::new(user.steam_user_data_folder.as_str())
                                     .join("config/grid");
-                                let path = grid_folder.join(image_type.file_name(shortcut.app_id));
+                                let path = grid_folder.join(&image_file_name);
                                 println!(
                                     "Downloading {} to {}",
                                     image.url,
@@ -162,24 +222,110 @@ async fn main() -> Result<(), Box<dyn Error>> {
                                 let response = reqwest::get(image.url).await?;
                                 let content = response.bytes().await?;
                                 file.write(&content).unwrap();
+                            } else {
+                                // SteamGridDB has no art of this type for this game;
+                                // remember that so we don't keep asking.
+                                search.record_no_art(shortcut.app_id, &image_file_name);
                             }
                         }
                     }
                 }
                 Err(err) => println!("Error getting images: {}", err),
             }
+            // Persist any no-art markers recorded for this image type before
+            // moving on, for the same reason the search loop saves eagerly.
+            search.save();
         }
     }
 
-    search.save();
+    Ok(())
+}
 
+/// Pick the user whose `shortcuts.vdf` manual `add`/`list` should operate
+/// on: the most recently logged-in account if we could identify one,
+/// otherwise whichever user folder we found first.
+fn select_user(users: &[SteamUsersInfo]) -> Option<&SteamUsersInfo> {
+    users
+        .iter()
+        .find(|user| matches!(&user.login_user, Some(login_user) if login_user.most_recent))
+        .or_else(|| users.first())
+}
+
+fn add_shortcut(add: AddCommand) -> Result<(), Box<dyn Error>> {
+    let users = get_shortcuts_paths()?;
+    let user = select_user(&users).ok_or_else(|| {
+        Box::new(SteamUsersDataEmpty {
+            location_tried: "userdata".to_string(),
+        })
+    })?;
+    let shortcut_info = get_shortcuts_for_user(user);
+    let mut shortcuts = shortcut_info.shortcuts;
+
+    let (exe, launch_options) = add
+        .command
+        .split_first()
+        .map(|(exe, args)| (exe.clone(), args.join(" ")))
+        .ok_or("No command given, nothing to add")?;
+
+    let app_id = calculate_app_id(&exe, &add.name);
+    let shortcut = Shortcut::new(
+        app_id,
+        &add.name,
+        &exe,
+        &add.start_dir,
+        &add.icon,
+        "",
+        &launch_options,
+    );
+    shortcuts.push(shortcut.to_owned());
+
+    let borrowed = shortcuts.iter().map(|f| f.borrow()).collect();
+    let new_content = shortcuts_to_bytes(&borrowed);
+    let mut file = File::create(shortcut_info.path)?;
+    file.write_all(new_content.as_slice())?;
+
+    println!("Added \"{}\" for user: {}", add.name, user.display_name());
+    Ok(())
+}
+
+fn list_shortcuts(list: ListCommand) -> Result<(), Box<dyn Error>> {
+    let users = get_shortcuts_paths()?;
+    let user = select_user(&users).ok_or_else(|| {
+        Box::new(SteamUsersDataEmpty {
+            location_tried: "userdata".to_string(),
+        })
+    })?;
+    let shortcut_info = get_shortcuts_for_user(user);
+
+    println!(
+        "{} shortcut(s) for user: {}",
+        shortcut_info.shortcuts.len(),
+        user.display_name()
+    );
+    for shortcut in &shortcut_info.shortcuts {
+        if list.verbose {
+            println!("{:#?}", shortcut);
+        } else {
+            println!(
+                "{} -> {} (tags: {})",
+                shortcut.app_name,
+                shortcut.exe,
+                shortcut.tags.join(", ")
+            );
+        }
+    }
     Ok(())
 }
 
 pub enum ImageType {
     Hero,
+    /// The tall 600x900 grid image shown in the library view.
     Grid,
+    /// The wide 920x430 landscape capsule Steam shows in Big Picture mode.
+    WideGrid,
     Logo,
+    /// The square icon used for the taskbar/shortcut icon.
+    Icon,
 }
 
 impl ImageType {
@@ -187,7 +333,26 @@ impl ImageType {
         match self {
             ImageType::Hero => format!("{}_hero.png", app_id),
             ImageType::Grid => format!("{}p.png", app_id),
+            ImageType::WideGrid => format!("{}.png", app_id),
             ImageType::Logo => format!("{}_logo.png", app_id),
+            ImageType::Icon => format!("{}_icon.png", app_id),
+        }
+    }
+
+    pub fn query_type(&self) -> steamgriddb_api::query_parameters::QueryType {
+        use steamgriddb_api::query_parameters::{GridDimentions, GridQueryParameters, QueryType};
+        match self {
+            ImageType::Hero => QueryType::Hero(None),
+            ImageType::Grid => QueryType::Grid(Some(GridQueryParameters {
+                dimentions: Some(&[GridDimentions::D600x900]),
+                ..Default::default()
+            })),
+            ImageType::WideGrid => QueryType::Grid(Some(GridQueryParameters {
+                dimentions: Some(&[GridDimentions::D920x430]),
+                ..Default::default()
+            })),
+            ImageType::Logo => QueryType::Logo(None),
+            ImageType::Icon => QueryType::Icon(None),
         }
     }
 }
@@ -248,31 +413,39 @@ impl Error for SteamUsersDataEmpty {
 struct SteamUsersInfo {
     pub steam_user_data_folder: String,
     pub shortcut_path: Option<String>,
+    pub login_user: Option<LoginUser>,
+}
+
+impl SteamUsersInfo {
+    /// A human-readable identity for logging, falling back to the raw
+    /// userdata folder when we couldn't match it to a `loginusers.vdf` entry.
+    pub fn display_name(&self) -> String {
+        match &self.login_user {
+            Some(user) if !user.persona_name.is_empty() => user.persona_name.clone(),
+            _ => self.steam_user_data_folder.clone(),
+        }
+    }
 }
 
 /// Get the paths to the steam users shortcuts (one for each user)
 fn get_shortcuts_paths() -> Result<Vec<SteamUsersInfo>, Box<dyn Error>> {
-    #[cfg(target_os = "windows")]
-    let path_string = {
-        let key = "PROGRAMFILES(X86)";
-        let program_files = env::var(key)?;
-        format!(
-            "{program_files}//Steam//userdata//",
-            program_files = program_files
-        )
-    };
-    #[cfg(target_os = "linux")]
-    let path_string = {
-        let home = std::env::var("HOME")?;
-        format!("{}/.steam/steam/userdata/", home)
-    };
-
-    let user_data_path = Path::new(path_string.as_str());
+    let steam_location = steam_location::find_steam().ok_or_else(|| {
+        Box::new(SteamFolderNotFound {
+            location_tried: "well known Steam install locations".to_string(),
+        })
+    })?;
+    let user_data_path = steam_location.join("userdata");
     if !user_data_path.exists() {
         return Result::Err(Box::new(SteamFolderNotFound {
-            location_tried: path_string,
+            location_tried: user_data_path.to_string_lossy().to_string(),
         }));
     }
+
+    let login_users_by_account_id: HashMap<u32, LoginUser> = loginusers::get_users(&steam_location)
+        .into_iter()
+        .map(|user| (user.account_id(), user))
+        .collect();
+
     let user_folders = std::fs::read_dir(&user_data_path)?;
     let users_info = user_folders
         .filter_map(|f| f.ok())
@@ -287,9 +460,15 @@ fn get_shortcuts_paths() -> Result<Vec<SteamUsersInfo>, Box<dyn Error>> {
             if shortcuts_path.exists() {
                 shortcuts_path_op = Some(shortcuts_path.to_str().unwrap().to_string());
             }
+            let login_user = folder
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u32>().ok())
+                .and_then(|account_id| login_users_by_account_id.get(&account_id).cloned());
             SteamUsersInfo {
                 steam_user_data_folder: folder_str.to_string(),
                 shortcut_path: shortcuts_path_op,
+                login_user,
             }
         })
         .collect();
@@ -0,0 +1,96 @@
+use std::fmt;
+
+use steam_shortcuts_util::shortcut::ShortcutOwned;
+
+use crate::platform::Platform;
+use crate::settings::OriginSettings;
+
+#[derive(Debug)]
+pub struct OriginError {
+    pub message: String,
+}
+
+impl fmt::Display for OriginError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for OriginError {}
+
+pub struct OriginPlatform {
+    settings: OriginSettings,
+}
+
+impl OriginPlatform {
+    pub fn new(settings: OriginSettings) -> Self {
+        OriginPlatform { settings }
+    }
+
+    /// Origin writes one registry key per installed title under
+    /// `HKLM\SOFTWARE\WOW6432Node\Origin Games\<id>`, holding `DisplayName`
+    /// and `Install Dir` values.
+    #[cfg(target_os = "windows")]
+    fn installed_games(&self) -> Result<Vec<ShortcutOwned>, OriginError> {
+        use winreg::enums::HKEY_LOCAL_MACHINE;
+        use winreg::RegKey;
+
+        let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+        let games_key = match hklm.open_subkey(r"SOFTWARE\WOW6432Node\Origin Games") {
+            Ok(key) => key,
+            Err(_) => return Ok(vec![]),
+        };
+
+        let mut shortcuts = vec![];
+        for id in games_key.enum_keys().filter_map(|id| id.ok()) {
+            let game_key = match games_key.open_subkey(&id) {
+                Ok(key) => key,
+                Err(_) => continue,
+            };
+            let name: String = match game_key.get_value("DisplayName") {
+                Ok(name) => name,
+                Err(_) => continue,
+            };
+            let install_dir: String = game_key.get_value("Install Dir").unwrap_or_default();
+
+            let app_id = steam_shortcuts_util::app_id_generator::calculate_app_id(
+                &format!("origin2://game/launch?offerIds={}", id),
+                &name,
+            );
+            let shortcut = steam_shortcuts_util::shortcut::Shortcut::new(
+                app_id,
+                &name,
+                &format!("origin2://game/launch?offerIds={}", id),
+                &install_dir,
+                "",
+                "",
+                "",
+            );
+            let mut shortcut_owned = shortcut.to_owned();
+            shortcut_owned.tags.push(self.name().to_string());
+            shortcuts.push(shortcut_owned);
+        }
+        Ok(shortcuts)
+    }
+
+    // Origin has no native Linux client, so there is nothing to scan outside
+    // of Windows (or a Windows prefix, which we don't attempt to locate).
+    #[cfg(not(target_os = "windows"))]
+    fn installed_games(&self) -> Result<Vec<ShortcutOwned>, OriginError> {
+        Ok(vec![])
+    }
+}
+
+impl Platform<ShortcutOwned, OriginError> for OriginPlatform {
+    fn enabled(&self) -> bool {
+        self.settings.enabled
+    }
+
+    fn name(&self) -> &str {
+        "Origin"
+    }
+
+    fn get_shortcuts(&self) -> Result<Vec<ShortcutOwned>, OriginError> {
+        self.installed_games()
+    }
+}
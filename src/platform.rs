@@ -0,0 +1,24 @@
+use std::fmt::{Debug, Display};
+
+use steam_shortcuts_util::shortcut::ShortcutOwned;
+
+/// A source of non-Steam games that can be turned into `ShortcutOwned`s.
+///
+/// Each launcher BoilR knows how to scan (Epic, Legendary, GOG, ...)
+/// implements this trait so `update_platform_shortcuts` can treat them all
+/// the same way: check `enabled()`, pull the shortcuts (each platform tags
+/// its own with `name()`), and replace the previously tagged entries.
+pub trait Platform<T, E>
+where
+    T: Into<ShortcutOwned>,
+    E: Debug + Display,
+{
+    /// Whether the user has turned this platform on in settings.
+    fn enabled(&self) -> bool;
+
+    /// The launcher name, used both for logging and as the shortcut tag that
+    /// lets `update_platform_shortcuts` find and replace its own entries.
+    fn name(&self) -> &str;
+
+    fn get_shortcuts(&self) -> Result<Vec<T>, E>;
+}
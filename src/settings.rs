@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct SteamGridDbSettings {
+    pub enabled: bool,
+    pub auth_key: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct EpicGamesSettings {
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct LegendarySettings {
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct GogSettings {
+    pub enabled: bool,
+    /// Override for `galaxy-2.0.db`, in case Galaxy was installed somewhere
+    /// other than the default `%PROGRAMDATA%` location.
+    pub galaxy_db_path: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ItchSettings {
+    pub enabled: bool,
+    /// Override for butler's `db/butler.db`.
+    pub butler_db_path: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct OriginSettings {
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct UbisoftSettings {
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct WebsiteEntry {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct WebLauncherSettings {
+    pub enabled: bool,
+    /// Overrides the auto-detected browser (Chrome, Chromium, Edge, or
+    /// Firefox) used to launch websites as their own window.
+    pub browser_path: Option<String>,
+    pub websites: Vec<WebsiteEntry>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Settings {
+    pub epic_games: EpicGamesSettings,
+    pub legendary: LegendarySettings,
+    pub gog: GogSettings,
+    pub itch: ItchSettings,
+    pub origin: OriginSettings,
+    pub ubisoft: UbisoftSettings,
+    pub web_launcher: WebLauncherSettings,
+    pub steamgrid_db: SteamGridDbSettings,
+}
+
+impl Settings {
+    pub fn new() -> Result<Self, Box<dyn Error>> {
+        Ok(confy::load("boilr")?)
+    }
+}
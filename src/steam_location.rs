@@ -0,0 +1,152 @@
+use std::path::{Path, PathBuf};
+
+/// Candidate locations to probe for a Steam install, in priority order.
+///
+/// Checked against `$HOME` so this works whether Steam was installed from a
+/// distro package, the official installer script, or as a Flatpak.
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+const LINUX_STEAM_CANDIDATES: &[&str] = &[
+    ".local/share/Steam",
+    ".steam/steam",
+    ".steam/root",
+    ".var/app/com.valvesoftware.Steam/.local/share/Steam",
+];
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+fn looks_like_steam_install(path: &Path) -> bool {
+    path.join("steam.sh").exists() || path.join("ubuntu12_32/steam").exists()
+}
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+fn find_steam_in(home: &Path) -> Option<PathBuf> {
+    LINUX_STEAM_CANDIDATES
+        .iter()
+        .map(|candidate| home.join(candidate))
+        .find(|path| looks_like_steam_install(path))
+}
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+fn find_steam_impl() -> Option<PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    find_steam_in(Path::new(&home))
+}
+
+#[cfg(target_os = "windows")]
+fn looks_like_steam_install(path: &Path) -> bool {
+    path.join("steam.exe").exists()
+}
+
+#[cfg(target_os = "windows")]
+fn find_steam_via_registry() -> Option<PathBuf> {
+    use winreg::enums::HKEY_LOCAL_MACHINE;
+    use winreg::RegKey;
+
+    let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+    // 64-bit Windows reflects 32-bit writers (like the Steam installer) into
+    // Wow6432Node, so check both locations.
+    for key_path in [
+        r"SOFTWARE\Valve\Steam",
+        r"SOFTWARE\Wow6432Node\Valve\Steam",
+    ] {
+        if let Ok(key) = hklm.open_subkey(key_path) {
+            if let Ok(install_path) = key.get_value::<String, _>("InstallPath") {
+                let path = PathBuf::from(install_path);
+                if looks_like_steam_install(&path) {
+                    return Some(path);
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(target_os = "windows")]
+fn find_steam_impl() -> Option<PathBuf> {
+    find_steam_via_registry()
+}
+
+/// Locate the Steam installation directory on this machine.
+///
+/// On Windows this reads the `InstallPath` value Steam writes to
+/// `HKEY_LOCAL_MACHINE\SOFTWARE\Valve\Steam` (falling back to the
+/// `Wow6432Node` mirror used on 64-bit Windows). On Linux/FreeBSD it probes
+/// an ordered list of well-known locations, including the Flatpak sandbox
+/// path, and picks the first one that actually looks like a Steam install.
+pub fn find_steam() -> Option<PathBuf> {
+    find_steam_impl()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh, uniquely-named directory under the OS temp dir, so
+    /// concurrently-running tests don't trip over each other's fake installs.
+    #[cfg(any(target_os = "linux", target_os = "freebsd"))]
+    fn unique_temp_dir(label: &str) -> PathBuf {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::SeqCst);
+        std::env::temp_dir().join(format!(
+            "boilr_test_steam_location_{}_{}_{}",
+            std::process::id(),
+            label,
+            id
+        ))
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "freebsd"))]
+    #[test]
+    fn finds_a_package_install_under_local_share() {
+        let home = unique_temp_dir("package_install");
+        let steam_dir = home.join(".local/share/Steam");
+        std::fs::create_dir_all(&steam_dir).unwrap();
+        std::fs::write(steam_dir.join("steam.sh"), "").unwrap();
+
+        assert_eq!(find_steam_in(&home), Some(steam_dir));
+
+        std::fs::remove_dir_all(&home).unwrap();
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "freebsd"))]
+    #[test]
+    fn prefers_local_share_over_dot_steam_when_both_exist() {
+        let home = unique_temp_dir("priority");
+        let preferred = home.join(".local/share/Steam");
+        let other = home.join(".steam/steam");
+        for dir in [&preferred, &other] {
+            std::fs::create_dir_all(dir).unwrap();
+            std::fs::write(dir.join("steam.sh"), "").unwrap();
+        }
+
+        assert_eq!(find_steam_in(&home), Some(preferred));
+
+        std::fs::remove_dir_all(&home).unwrap();
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "freebsd"))]
+    #[test]
+    fn skips_a_directory_without_the_steam_binary() {
+        let home = unique_temp_dir("no_binary");
+        let empty_candidate = home.join(".local/share/Steam");
+        let real_install = home.join(".steam/steam");
+        std::fs::create_dir_all(&empty_candidate).unwrap();
+        std::fs::create_dir_all(real_install.join("ubuntu12_32")).unwrap();
+        std::fs::write(real_install.join("ubuntu12_32/steam"), "").unwrap();
+
+        assert_eq!(find_steam_in(&home), Some(real_install));
+
+        std::fs::remove_dir_all(&home).unwrap();
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "freebsd"))]
+    #[test]
+    fn returns_none_when_nothing_looks_like_steam() {
+        let home = unique_temp_dir("nothing");
+        std::fs::create_dir_all(home.join(".local/share/Steam")).unwrap();
+
+        assert_eq!(find_steam_in(&home), None);
+
+        std::fs::remove_dir_all(&home).unwrap();
+    }
+}
@@ -0,0 +1,7 @@
+use steamgriddb_api::Client;
+
+/// Build the SteamGridDB API client BoilR talks to everywhere else, so the
+/// auth key only gets wired up to the `steamgriddb_api` crate in one place.
+pub fn new_client(auth_key: String) -> Client {
+    Client::new(auth_key)
+}
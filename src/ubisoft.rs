@@ -0,0 +1,99 @@
+use std::fmt;
+
+use steam_shortcuts_util::shortcut::ShortcutOwned;
+
+use crate::platform::Platform;
+use crate::settings::UbisoftSettings;
+
+#[derive(Debug)]
+pub struct UbisoftError {
+    pub message: String,
+}
+
+impl fmt::Display for UbisoftError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for UbisoftError {}
+
+pub struct UbisoftPlatform {
+    settings: UbisoftSettings,
+}
+
+impl UbisoftPlatform {
+    pub fn new(settings: UbisoftSettings) -> Self {
+        UbisoftPlatform { settings }
+    }
+
+    /// Ubisoft Connect (formerly Uplay) writes one registry key per install
+    /// under `HKLM\SOFTWARE\WOW6432Node\Ubisoft\Launcher\Installs\<game-id>`,
+    /// holding an `InstallDir` value. It launches games through its own
+    /// `uplay://launch/<game-id>/0` protocol handler.
+    #[cfg(target_os = "windows")]
+    fn installed_games(&self) -> Result<Vec<ShortcutOwned>, UbisoftError> {
+        use winreg::enums::HKEY_LOCAL_MACHINE;
+        use winreg::RegKey;
+
+        let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+        let installs_key =
+            match hklm.open_subkey(r"SOFTWARE\WOW6432Node\Ubisoft\Launcher\Installs") {
+                Ok(key) => key,
+                Err(_) => return Ok(vec![]),
+            };
+
+        let mut shortcuts = vec![];
+        for game_id in installs_key.enum_keys().filter_map(|id| id.ok()) {
+            let install_key = match installs_key.open_subkey(&game_id) {
+                Ok(key) => key,
+                Err(_) => continue,
+            };
+            let install_dir: String = match install_key.get_value("InstallDir") {
+                Ok(dir) => dir,
+                Err(_) => continue,
+            };
+            // Ubisoft Connect doesn't expose a friendly name in this key, so
+            // fall back to the install folder's own name.
+            let name = std::path::Path::new(&install_dir)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| game_id.clone());
+
+            let exe = format!("uplay://launch/{}/0", game_id);
+            let app_id = steam_shortcuts_util::app_id_generator::calculate_app_id(&exe, &name);
+            let shortcut = steam_shortcuts_util::shortcut::Shortcut::new(
+                app_id,
+                &name,
+                &exe,
+                &install_dir,
+                "",
+                "",
+                "",
+            );
+            let mut shortcut_owned = shortcut.to_owned();
+            shortcut_owned.tags.push(self.name().to_string());
+            shortcuts.push(shortcut_owned);
+        }
+        Ok(shortcuts)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn installed_games(&self) -> Result<Vec<ShortcutOwned>, UbisoftError> {
+        Ok(vec![])
+    }
+}
+
+impl Platform<ShortcutOwned, UbisoftError> for UbisoftPlatform {
+    fn enabled(&self) -> bool {
+        self.settings.enabled
+    }
+
+    fn name(&self) -> &str {
+        "Ubisoft Connect"
+    }
+
+    fn get_shortcuts(&self) -> Result<Vec<ShortcutOwned>, UbisoftError> {
+        self.installed_games()
+    }
+}
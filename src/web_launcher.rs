@@ -0,0 +1,229 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use steam_shortcuts_util::shortcut::ShortcutOwned;
+
+use crate::platform::Platform;
+use crate::settings::WebLauncherSettings;
+
+#[derive(Debug)]
+pub struct WebLauncherError {
+    pub message: String,
+}
+
+impl fmt::Display for WebLauncherError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for WebLauncherError {}
+
+/// Which family a detected browser belongs to, since they don't all take the
+/// same flags for opening a single site as its own window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserKind {
+    /// Chrome, Chromium, and Edge all understand Chrome's `--app=` flag.
+    Chrome,
+    /// Firefox has no `--app=` equivalent; the closest is `-new-window`.
+    Firefox,
+}
+
+impl BrowserKind {
+    /// Guess the browser family from its executable name, for when the user
+    /// overrides `browser_path` with something we didn't detect ourselves.
+    fn from_path(path: &Path) -> Self {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        if file_name.contains("firefox") {
+            BrowserKind::Firefox
+        } else {
+            BrowserKind::Chrome
+        }
+    }
+
+    /// The launch options that open `url` as its own window.
+    fn launch_options(&self, url: &str) -> String {
+        match self {
+            BrowserKind::Chrome => format!("--app={}", url),
+            BrowserKind::Firefox => format!("-new-window {}", url),
+        }
+    }
+}
+
+/// Candidate browser binaries, checked in order of preference, paired with
+/// their `BrowserKind` so we know how to launch a site with each one.
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+const LINUX_BROWSER_CANDIDATES: &[(&str, BrowserKind)] = &[
+    ("google-chrome", BrowserKind::Chrome),
+    ("chromium", BrowserKind::Chrome),
+    ("chromium-browser", BrowserKind::Chrome),
+    ("microsoft-edge", BrowserKind::Chrome),
+    ("firefox", BrowserKind::Firefox),
+    (
+        "/var/lib/flatpak/exports/bin/com.google.Chrome",
+        BrowserKind::Chrome,
+    ),
+    (
+        "/var/lib/flatpak/exports/bin/org.chromium.Chromium",
+        BrowserKind::Chrome,
+    ),
+    (
+        "/var/lib/flatpak/exports/bin/com.microsoft.Edge",
+        BrowserKind::Chrome,
+    ),
+    (
+        "/var/lib/flatpak/exports/bin/org.mozilla.firefox",
+        BrowserKind::Firefox,
+    ),
+];
+
+#[cfg(target_os = "windows")]
+const WINDOWS_BROWSER_CANDIDATES: &[(&str, BrowserKind)] = &[
+    (r"Google\Chrome\Application\chrome.exe", BrowserKind::Chrome),
+    (r"Microsoft\Edge\Application\msedge.exe", BrowserKind::Chrome),
+    (r"Mozilla Firefox\firefox.exe", BrowserKind::Firefox),
+];
+
+fn is_executable(path: &Path) -> bool {
+    path.exists()
+}
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+fn find_browser_impl() -> Option<(PathBuf, BrowserKind)> {
+    for (candidate, kind) in LINUX_BROWSER_CANDIDATES {
+        let path = Path::new(candidate);
+        if path.is_absolute() {
+            if is_executable(path) {
+                return Some((path.to_path_buf(), *kind));
+            }
+            continue;
+        }
+        if let Ok(path_var) = std::env::var("PATH") {
+            for dir in std::env::split_paths(&path_var) {
+                let full_path = dir.join(candidate);
+                if is_executable(&full_path) {
+                    return Some((full_path, *kind));
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(target_os = "windows")]
+fn find_browser_impl() -> Option<(PathBuf, BrowserKind)> {
+    for program_files_env in ["PROGRAMFILES", "PROGRAMFILES(X86)"] {
+        let program_files = match std::env::var(program_files_env) {
+            Ok(value) => value,
+            Err(_) => continue,
+        };
+        for (candidate, kind) in WINDOWS_BROWSER_CANDIDATES {
+            let path = Path::new(&program_files).join(candidate);
+            if is_executable(&path) {
+                return Some((path, *kind));
+            }
+        }
+    }
+    None
+}
+
+/// Find a browser to launch websites with, and which family it belongs to.
+pub fn find_browser() -> Option<(PathBuf, BrowserKind)> {
+    find_browser_impl()
+}
+
+pub struct WebLauncherPlatform {
+    settings: WebLauncherSettings,
+}
+
+impl WebLauncherPlatform {
+    pub fn new(settings: WebLauncherSettings) -> Self {
+        WebLauncherPlatform { settings }
+    }
+}
+
+impl Platform<ShortcutOwned, WebLauncherError> for WebLauncherPlatform {
+    fn enabled(&self) -> bool {
+        self.settings.enabled && !self.settings.websites.is_empty()
+    }
+
+    fn name(&self) -> &str {
+        "Websites"
+    }
+
+    fn get_shortcuts(&self) -> Result<Vec<ShortcutOwned>, WebLauncherError> {
+        let (browser, browser_kind) = match self.settings.browser_path.clone() {
+            Some(path) => {
+                let path = PathBuf::from(path);
+                let kind = BrowserKind::from_path(&path);
+                (path, kind)
+            }
+            None => find_browser().ok_or_else(|| WebLauncherError {
+                message: "Could not find a browser (Chrome, Chromium, Edge, or Firefox) to launch websites with"
+                    .to_string(),
+            })?,
+        };
+        let browser = browser.to_string_lossy().to_string();
+
+        let mut shortcuts = vec![];
+        for website in &self.settings.websites {
+            let launch_options = browser_kind.launch_options(&website.url);
+            let app_id =
+                steam_shortcuts_util::app_id_generator::calculate_app_id(&browser, &website.name);
+            let shortcut = steam_shortcuts_util::shortcut::Shortcut::new(
+                app_id,
+                &website.name,
+                &browser,
+                "",
+                "",
+                "",
+                &launch_options,
+            );
+            let mut shortcut_owned = shortcut.to_owned();
+            shortcut_owned.tags.push(self.name().to_string());
+            shortcuts.push(shortcut_owned);
+        }
+        Ok(shortcuts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_firefox_from_path() {
+        assert_eq!(
+            BrowserKind::from_path(Path::new("/usr/bin/firefox")),
+            BrowserKind::Firefox
+        );
+        assert_eq!(
+            BrowserKind::from_path(Path::new(r"C:\Program Files\Mozilla Firefox\firefox.exe")),
+            BrowserKind::Firefox
+        );
+    }
+
+    #[test]
+    fn defaults_unknown_paths_to_chrome() {
+        assert_eq!(
+            BrowserKind::from_path(Path::new("/usr/bin/google-chrome")),
+            BrowserKind::Chrome
+        );
+    }
+
+    #[test]
+    fn firefox_does_not_use_app_flag() {
+        let options = BrowserKind::Firefox.launch_options("https://example.com");
+        assert!(!options.contains("--app="));
+        assert_eq!(options, "-new-window https://example.com");
+    }
+
+    #[test]
+    fn chrome_uses_app_flag() {
+        let options = BrowserKind::Chrome.launch_options("https://example.com");
+        assert_eq!(options, "--app=https://example.com");
+    }
+}